@@ -0,0 +1,144 @@
+use {
+    heapless::Vec as HeaplessVec,
+    std::{
+        error::Error,
+        fmt::{
+            Display,
+            Formatter,
+            Result as FmtResult,
+        },
+        iter::repeat,
+    },
+};
+
+/// A cursor for reading typed primitives out of a byte slice, tracking the position for error
+/// reporting.
+#[derive(Clone, Copy, Debug)]
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor over `bytes`, starting at position `0`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// The number of bytes already read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The bytes not yet read.
+    pub fn remainder(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Reads `N` bytes.
+    pub fn read_bytes<const N: usize>(&mut self) -> Result<[u8; N], CursorError> {
+        if self.bytes.len() < N {
+            return Result::Err(CursorError::UnexpectedEof {
+                position: self.position,
+            });
+        }
+
+        let (bytes, remainder) = self.bytes.split_at(N);
+        self.bytes = remainder;
+        self.position += N;
+        Result::Ok(bytes.try_into().unwrap())
+    }
+
+    /// Reads a `u8`.
+    pub fn read_u8(&mut self) -> Result<u8, CursorError> {
+        Result::Ok(self.read_bytes::<1>()?[0])
+    }
+
+    /// Reads a little-endian `u16`.
+    pub fn read_u16_le(&mut self) -> Result<u16, CursorError> {
+        Result::Ok(u16::from_le_bytes(self.read_bytes()?))
+    }
+
+    /// Reads a little-endian `u32`.
+    pub fn read_u32_le(&mut self) -> Result<u32, CursorError> {
+        Result::Ok(u32::from_le_bytes(self.read_bytes()?))
+    }
+
+    /// Reads a name padded with trailing `b'0'` bytes up to `N` bytes, stopping at the first
+    /// `b'0'`.
+    pub fn read_zero_padded_name<const N: usize>(
+        &mut self,
+    ) -> Result<HeaplessVec<u8, N>, CursorError> {
+        Result::Ok(
+            self.read_bytes::<N>()?
+                .into_iter()
+                .take_while(|c| *c != b'0')
+                .collect(),
+        )
+    }
+}
+
+/// A cursor for writing typed primitives into a growable byte buffer.
+#[derive(Debug)]
+pub struct Writer<'a> {
+    bytes: &'a mut Vec<u8>,
+}
+
+impl<'a> Writer<'a> {
+    /// Creates a writer appending to `bytes`.
+    pub fn new(bytes: &'a mut Vec<u8>) -> Self {
+        Self { bytes }
+    }
+
+    /// Writes raw bytes.
+    pub fn put_bytes(&mut self, bytes: &[u8]) {
+        self.bytes.extend_from_slice(bytes);
+    }
+
+    /// Writes a `u8`.
+    pub fn put_u8(&mut self, value: u8) {
+        self.put_bytes(&[value]);
+    }
+
+    /// Writes a little-endian `u16`.
+    pub fn put_u16_le(&mut self, value: u16) {
+        self.put_bytes(&value.to_le_bytes());
+    }
+
+    /// Writes a little-endian `u32`.
+    pub fn put_u32_le(&mut self, value: u32) {
+        self.put_bytes(&value.to_le_bytes());
+    }
+
+    /// Writes a name, padded with trailing `b'0'` bytes up to `N` bytes.
+    pub fn put_zero_padded_name<const N: usize>(&mut self, name: &HeaplessVec<u8, N>) {
+        self.put_bytes(name);
+        self.put_bytes(
+            &repeat(b'0')
+                .take(N - name.len())
+                .collect::<HeaplessVec<_, N>>(),
+        );
+    }
+}
+
+/// An error while reading from a [`Cursor`].
+#[derive(Debug)]
+pub enum CursorError {
+    /// The cursor ran out of bytes at the given position.
+    UnexpectedEof {
+        /// The position at which the read was attempted.
+        position: usize,
+    },
+}
+
+impl Display for CursorError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::UnexpectedEof { position } => {
+                write!(formatter, "unexpected end of input at byte {position}")
+            },
+        }
+    }
+}
+
+impl Error for CursorError {}
@@ -5,6 +5,13 @@ use {
         Trn,
         Wtb,
     },
+    futures::{
+        join,
+        stream::{
+            self,
+            StreamExt as _,
+        },
+    },
     hyper::{
         body::{
             aggregate,
@@ -14,6 +21,7 @@ use {
             Client,
             HttpConnector,
         },
+        header::CONTENT_ENCODING,
         http::uri::InvalidUri as UriError,
         Error as HyperError,
         StatusCode,
@@ -30,16 +38,30 @@ use {
             Formatter,
             Result as FmtResult,
         },
-        io::Read,
+        io::{
+            Error as IoError,
+            Read,
+        },
     },
 };
 
+#[cfg(feature = "compress-gzip")]
+use flate2::read::GzDecoder;
+#[cfg(feature = "compress-zstd")]
+use zstd::Decoder as ZstdDecoder;
+
 macro_rules! uri {
     ($name:literal) => {
         concat!("https://www.ffothello.org/wthor/base/", $name)
     };
 }
 
+/// The first year the WTHOR database covers.
+const FIRST_YEAR: u16 = 1977;
+
+/// The number of in-flight requests [`Downloader::download_all`] allows at once.
+const BATCH_SIZE: usize = 8;
+
 /// A database file downloader.
 #[derive(Clone, Debug)]
 pub struct Downloader {
@@ -63,14 +85,102 @@ impl Downloader {
         }
     }
 
-    async fn download(&self, uri: Uri) -> Result<impl Read, DownloadError> {
+    async fn download(&self, uri: Uri) -> Result<Box<dyn Read>, DownloadError> {
         let response = self.client.get(uri).await?;
 
         match response.status() {
-            StatusCode::OK => Result::Ok(aggregate(response).await?.reader()),
+            StatusCode::OK => {
+                let encoding = response
+                    .headers()
+                    .get(CONTENT_ENCODING)
+                    .map(|value| value.to_str().unwrap_or_default().to_owned());
+
+                let reader = aggregate(response).await?.reader();
+
+                match encoding.as_deref() {
+                    Option::None | Option::Some("identity") => Result::Ok(Box::new(reader)),
+                    #[cfg(feature = "compress-gzip")]
+                    Option::Some("gzip") => Result::Ok(Box::new(GzDecoder::new(reader))),
+                    #[cfg(feature = "compress-zstd")]
+                    Option::Some("zstd") => Result::Ok(Box::new(ZstdDecoder::new(reader)?)),
+                    Option::Some(encoding) => {
+                        Result::Err(DownloadError::UnsupportedEncoding(encoding.to_owned()))
+                    },
+                }
+            },
             _ => Result::Err(DownloadError::StatusCode(response.status())),
         }
     }
+
+    /// Downloads `WTHOR.JOU`, `WTHOR.TRN`, and every yearly `.wtb` file, probing upward from
+    /// [`FIRST_YEAR`] in batches of [`BATCH_SIZE`] concurrent requests until a batch turns up a
+    /// missing year, to auto-discover the latest year available.
+    ///
+    /// `progress`, if given, is called after each file finishes downloading with the number of
+    /// files completed so far.
+    pub async fn download_all(
+        &self,
+        mut progress: Option<impl FnMut(usize)>,
+    ) -> Result<Database, DownloadError> {
+        let mut completed = 0;
+
+        let mut report = |completed: &mut usize| {
+            *completed += 1;
+
+            if let Option::Some(progress) = &mut progress {
+                progress(*completed);
+            }
+        };
+
+        let (jou, trn) = join!(Jou::download(self), Trn::download(self));
+        let jou = jou?;
+        report(&mut completed);
+        let trn = trn?;
+        report(&mut completed);
+
+        let mut wtbs = Vec::new();
+        let mut year = FIRST_YEAR;
+
+        'discovery: loop {
+            let mut results: Vec<(u16, Result<Wtb, DownloadError>)> =
+                stream::iter(year..year + BATCH_SIZE as u16)
+                    .map(|year| async move { (year, Wtb::download(self, year).await) })
+                    .buffer_unordered(BATCH_SIZE)
+                    .collect()
+                    .await;
+
+            results.sort_unstable_by_key(|(year, _)| *year);
+
+            for (_, result) in results {
+                match result {
+                    Result::Ok(wtb) => {
+                        wtbs.push(wtb);
+                        report(&mut completed);
+                    },
+                    Result::Err(DownloadError::StatusCode(StatusCode::NOT_FOUND)) => {
+                        break 'discovery
+                    },
+                    Result::Err(error) => return Result::Err(error),
+                }
+            }
+
+            year += BATCH_SIZE as u16;
+        }
+
+        Result::Ok(Database { jou, trn, wtbs })
+    }
+}
+
+/// A downloaded, assembled copy of the full database: the jou and trn files, and every yearly
+/// wtb file [`Downloader::download_all`] could find.
+#[derive(Clone, Debug)]
+pub struct Database {
+    /// The jou file.
+    pub jou: Jou,
+    /// The trn file.
+    pub trn: Trn,
+    /// Every downloaded wtb file, in chronological order by year.
+    pub wtbs: Vec<Wtb>,
 }
 
 impl Jou {
@@ -123,6 +233,12 @@ pub enum DownloadError {
     Hyper(HyperError),
     /// See [`ReadError`].
     Read(ReadError),
+    /// The response's `Content-Encoding` header named an encoding this build was not compiled
+    /// to decode.
+    UnsupportedEncoding(String),
+    /// See [`Error`](IoError), raised while setting up a decoder for the response's
+    /// `Content-Encoding`.
+    Io(IoError),
 }
 
 impl Display for DownloadError {
@@ -132,6 +248,10 @@ impl Display for DownloadError {
             Self::StatusCode(code) => code.fmt(formatter),
             Self::Hyper(error) => error.fmt(formatter),
             Self::Read(error) => error.fmt(formatter),
+            Self::UnsupportedEncoding(encoding) => {
+                write!(formatter, "unsupported content encoding: {encoding}")
+            },
+            Self::Io(error) => error.fmt(formatter),
         }
     }
 }
@@ -155,3 +275,9 @@ impl From<ReadError> for DownloadError {
         Self::Read(error)
     }
 }
+
+impl From<IoError> for DownloadError {
+    fn from(error: IoError) -> Self {
+        Self::Io(error)
+    }
+}
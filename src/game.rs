@@ -0,0 +1,429 @@
+use {
+    crate::GameInfo,
+    othello::{
+        Game as OthelloGame,
+        Position,
+    },
+    std::{
+        error::Error,
+        fmt::{
+            Display,
+            Formatter,
+            Result as FmtResult,
+        },
+    },
+};
+
+/// The color of a disk, or of the side to move.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Color {
+    /// Black, which always moves first.
+    Black,
+    /// White.
+    White,
+}
+
+impl Color {
+    /// The other color.
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::Black => Self::White,
+            Self::White => Self::Black,
+        }
+    }
+}
+
+/// An `8x8` Othello board, backed by [`othello::Game`] for legality checks and move
+/// application.
+#[derive(Clone, Debug)]
+pub struct Board {
+    game: OthelloGame,
+    to_move: Color,
+}
+
+impl Board {
+    /// The standard opening position: the four center disks, Black to move.
+    pub fn opening() -> Self {
+        Self {
+            game: OthelloGame::default(),
+            to_move: Color::Black,
+        }
+    }
+
+    /// The side to move.
+    pub fn to_move(&self) -> Color {
+        self.to_move
+    }
+
+    /// The disk at `position`, if any.
+    pub fn at(&self, position: Position) -> Option<Color> {
+        let place = Self::place(position);
+        let [to_move, other] = self.game.get();
+
+        if to_move >> place & 1 != 0 {
+            Option::Some(self.to_move)
+        } else if other >> place & 1 != 0 {
+            Option::Some(self.to_move.opposite())
+        } else {
+            Option::None
+        }
+    }
+
+    /// The number of disks of `color`.
+    pub fn count(&self, color: Color) -> u8 {
+        let [to_move, other] = self.game.get();
+        let bits = if color == self.to_move { to_move } else { other };
+        bits.count_ones() as u8
+    }
+
+    /// Whether `color` has a legal move anywhere on the board.
+    pub fn has_legal_move(&self, color: Color) -> bool {
+        self.available_moves(color) != 0
+    }
+
+    /// Whether playing at `position` is legal for `color`.
+    pub fn is_legal_move(&self, color: Color, position: Position) -> bool {
+        self.available_moves(color) >> Self::place(position) & 1 != 0
+    }
+
+    /// Plays `position` for `color`, flipping the runs it captures and switching the side to
+    /// move.
+    ///
+    /// Returns `false`, leaving the board unchanged, if it is not `color`'s turn or the move is
+    /// not legal.
+    pub fn play(&mut self, color: Color, position: Position) -> bool {
+        if color != self.to_move {
+            return false;
+        }
+
+        match self.game.make_move(Self::place(position)) {
+            Option::Some(game) => {
+                self.game = game;
+                self.to_move = self.to_move.opposite();
+                true
+            },
+            Option::None => false,
+        }
+    }
+
+    /// Passes the turn for `color` without playing, switching the side to move.
+    ///
+    /// Returns `false`, leaving the board unchanged, if it is not `color`'s turn.
+    pub fn pass(&mut self, color: Color) -> bool {
+        if color != self.to_move {
+            return false;
+        }
+
+        self.game.pass_move();
+        self.to_move = self.to_move.opposite();
+        true
+    }
+
+    fn available_moves(&self, color: Color) -> u64 {
+        if color == self.to_move {
+            self.game.available_moves()
+        } else {
+            let mut other = self.game.clone();
+            other.pass_move();
+            other.available_moves()
+        }
+    }
+
+    fn place(position: Position) -> usize {
+        position.row() as usize * 8 + position.column() as usize
+    }
+}
+
+/// A single step of a replayed [`GameInfo`]: either a recorded move or a pass automatically
+/// inserted because the side to move had no legal move (WTHOR omits passes from the stream).
+#[derive(Clone, Copy, Hash, Debug)]
+pub enum Step {
+    /// `color` played at `position`.
+    Move {
+        /// The side that moved.
+        color: Color,
+        /// Where it moved.
+        position: Position,
+    },
+    /// `color` had no legal move and passed.
+    Pass {
+        /// The side that passed.
+        color: Color,
+    },
+}
+
+/// A [`GameInfo`]'s moves, replayed on a [`Board`] from the standard opening.
+#[derive(Clone, Debug)]
+pub struct Game {
+    board: Board,
+    steps: Vec<Step>,
+    score: u8,
+}
+
+impl Game {
+    /// Replays `game`'s moves from the standard opening, automatically inserting a pass
+    /// whenever the side to move has no legal move.
+    pub fn from_game_info(game: &GameInfo) -> Result<Self, GameError> {
+        let mut board = Board::opening();
+        let mut steps = Vec::new();
+
+        for &position in &game.moves {
+            let color = board.to_move();
+
+            if !board.has_legal_move(color) {
+                board.pass(color);
+                steps.push(Step::Pass { color });
+            }
+
+            let color = board.to_move();
+
+            if !board.play(color, position) {
+                return Result::Err(GameError::IllegalMove(position));
+            }
+
+            steps.push(Step::Move { color, position });
+        }
+
+        Result::Ok(Self {
+            board,
+            steps,
+            score: game.score,
+        })
+    }
+
+    /// The board after every recorded move, and every automatically inserted pass, has been
+    /// played.
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    /// The moves and passes played, in order.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Confirms that every move was legal (guaranteed by [`Self::from_game_info`] having
+    /// succeeded), that the final number of Black disks equals [`GameInfo::score`], and that
+    /// enough moves were played to reach the empties threshold used for
+    /// [`GameInfo::theoretical_score`], given the file's `calculation_depth`.
+    ///
+    /// `created_centry`, `created_year`, `created_month`, and `created_day` are the file's
+    /// creation date (as on [`Wtb`](crate::Wtb)), needed to resolve `calculation_depth == 0`:
+    /// it is only documented to mean `22` for files created on or after `01/01/2001`. For older
+    /// files this method has no documented interpretation of `0` to fall back on, so it reports
+    /// [`ValidationError::AmbiguousCalculationDepth`] rather than guessing.
+    pub fn validate(
+        &self,
+        calculation_depth: u8,
+        created_centry: u8,
+        created_year: u8,
+        created_month: u8,
+        created_day: u8,
+    ) -> Result<(), ValidationError> {
+        if self.board.count(Color::Black) != self.score {
+            return Result::Err(ValidationError::ScoreMismatch);
+        }
+
+        let effective_depth = match calculation_depth {
+            0 => {
+                let year = created_centry as u16 * 100 + created_year as u16;
+
+                if (year, created_month, created_day) >= (2001, 1, 1) {
+                    22
+                } else {
+                    return Result::Err(ValidationError::AmbiguousCalculationDepth);
+                }
+            },
+            depth => depth,
+        };
+
+        let moves_played = self
+            .steps
+            .iter()
+            .filter(|step| matches!(step, Step::Move { .. }))
+            .count();
+
+        let empties = 64 - 4 - moves_played;
+        let can_continue =
+            self.board.has_legal_move(Color::Black) || self.board.has_legal_move(Color::White);
+
+        if empties > effective_depth as usize && can_continue {
+            return Result::Err(ValidationError::InsufficientMoves);
+        }
+
+        Result::Ok(())
+    }
+}
+
+/// An error while replaying a [`GameInfo`].
+#[derive(Debug)]
+pub enum GameError {
+    /// `Position` was not a legal move for the side to move.
+    IllegalMove(Position),
+}
+
+impl Display for GameError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::IllegalMove(position) => {
+                write!(formatter, "{position:?} is not a legal move")
+            },
+        }
+    }
+}
+
+impl Error for GameError {}
+
+/// An error while validating a [`Game`] against the [`GameInfo`] it was replayed from.
+#[derive(Debug)]
+pub enum ValidationError {
+    /// The final number of Black disks does not match [`GameInfo::score`].
+    ScoreMismatch,
+    /// The game ended before enough moves were played to reach the empties threshold used for
+    /// [`GameInfo::theoretical_score`].
+    InsufficientMoves,
+    /// `calculation_depth` was `0` in a file created before `01/01/2001`, where `0`'s meaning is
+    /// not documented by the format.
+    AmbiguousCalculationDepth,
+}
+
+impl Display for ValidationError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::ScoreMismatch => formatter.write_str("the final score does not match"),
+            Self::InsufficientMoves => {
+                formatter.write_str("not enough moves were played to reach the empties threshold")
+            },
+            Self::AmbiguousCalculationDepth => formatter.write_str(
+                "calculation_depth is 0 in a file created before 01/01/2001, whose meaning is \
+                 not documented",
+            ),
+        }
+    }
+}
+
+impl Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn game_info(transcript: &str, score: u8) -> GameInfo {
+        GameInfo {
+            tournament: 0,
+            black_player: 0,
+            white_player: 0,
+            score,
+            theoretical_score: 0,
+            moves: GameInfo::from_transcript(transcript).unwrap(),
+        }
+    }
+
+    #[test]
+    fn replays_a_known_game() {
+        // The ten-move opening from the Othello literature known as the "Rose" (f5 d6 c3 d3 c4
+        // f4 f6 f3 e6 e7), reaching 6 Black disks and 8 White disks.
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 6);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert_eq!(game.board().count(Color::Black), 6);
+        assert_eq!(game.board().count(Color::White), 8);
+        assert_eq!(game.steps().len(), 10);
+        assert!(game
+            .steps()
+            .iter()
+            .all(|step| matches!(step, Step::Move { .. })));
+    }
+
+    #[test]
+    fn an_empty_move_list_is_valid() {
+        let info = game_info("", 2);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert_eq!(game.steps().len(), 0);
+        assert_eq!(game.board().count(Color::Black), 2);
+        assert_eq!(game.board().count(Color::White), 2);
+    }
+
+    #[test]
+    fn inserts_a_pass_when_a_side_has_no_legal_move() {
+        // Reaches a position, after 12 moves, where Black has no legal move and White does; the
+        // 13th recorded move (e3) is White's, so replaying it must insert an automatic pass for
+        // Black in between.
+        let info = game_info("d3c3b3b2b1a1f5d2d1c1b4e1e3", 9);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(game
+            .steps()
+            .iter()
+            .any(|step| matches!(step, Step::Pass { color: Color::Black })));
+    }
+
+    #[test]
+    fn rejects_an_illegal_move() {
+        // f5 is a legal opening move for Black; replaying it again for White lands on an
+        // occupied square.
+        let info = game_info("f5f5", 0);
+
+        assert!(matches!(
+            Game::from_game_info(&info),
+            Result::Err(GameError::IllegalMove(_)),
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_insufficient_moves() {
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 6);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(matches!(
+            game.validate(1, 20, 23, 1, 1),
+            Result::Err(ValidationError::InsufficientMoves),
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_a_score_mismatch() {
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 0);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(matches!(
+            game.validate(60, 20, 23, 1, 1),
+            Result::Err(ValidationError::ScoreMismatch),
+        ));
+    }
+
+    #[test]
+    fn validate_accepts_a_game_within_the_calculation_depth() {
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 6);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(game.validate(60, 20, 23, 1, 1).is_ok());
+    }
+
+    #[test]
+    fn validate_resolves_a_zero_calculation_depth_as_22_after_2001() {
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 6);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(matches!(
+            game.validate(0, 20, 1, 1, 1),
+            Result::Err(ValidationError::InsufficientMoves),
+        ));
+        assert!(matches!(
+            game.validate(22, 20, 1, 1, 1),
+            Result::Err(ValidationError::InsufficientMoves),
+        ));
+    }
+
+    #[test]
+    fn validate_rejects_a_zero_calculation_depth_before_2001() {
+        let info = game_info("f5d6c3d3c4f4f6f3e6e7", 6);
+        let game = Game::from_game_info(&info).unwrap();
+
+        assert!(matches!(
+            game.validate(0, 19, 99, 12, 31),
+            Result::Err(ValidationError::AmbiguousCalculationDepth),
+        ));
+    }
+}
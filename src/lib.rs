@@ -1,11 +1,31 @@
 //! A crate for [WTHOR Database](https://www.ffothello.org/informatique/la-base-wthor).
 
+mod cursor;
 #[cfg(feature = "download")]
 mod download;
+mod game;
+mod slice;
 
+pub use crate::cursor::{
+    Cursor,
+    CursorError,
+    Writer,
+};
 #[cfg(feature = "download")]
 pub use crate::download::*;
+pub use crate::game::{
+    Board,
+    Color,
+    Game,
+    GameError,
+    Step,
+    ValidationError,
+};
 use {
+    crate::slice::{
+        as_chunks,
+        split,
+    },
     heapless::Vec as HeaplessVec,
     othello::Position,
     std::{
@@ -70,6 +90,64 @@ impl Jou {
         write_names(&mut w, &self.players, number_of_players)?;
         Result::Ok(())
     }
+
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<JouRef<'_>, ReadError> {
+        JouRef::from_bytes(bytes)
+    }
+}
+
+/// A zero-copy view of a [`Jou`] parsed from an in-memory byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct JouRef<'a> {
+    /// The centry when the file was created.
+    pub created_centry: u8,
+    /// The year when the file was created.
+    pub created_year: u8,
+    /// The month when the file was created.
+    pub created_month: u8,
+    /// The day when the file was created.
+    pub created_day: u8,
+    players: &'a [[u8; 20]],
+}
+
+impl<'a> JouRef<'a> {
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ReadError> {
+        let (header, remainder) = split::<u8, 16>(bytes).ok_or(ReadError::InvalidFormat)?;
+
+        let (created_centry, created_year, created_month, created_day, number_of_players) =
+            decode_names_header(header)?;
+
+        Result::Ok(Self {
+            created_centry,
+            created_year,
+            created_month,
+            created_day,
+            players: as_chunks(remainder, number_of_players as usize)
+                .ok_or(ReadError::InvalidFormat)?,
+        })
+    }
+
+    /// The number of players.
+    pub fn len(&self) -> usize {
+        self.players.len()
+    }
+
+    /// Whether there are no players.
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    /// Decodes the name of the player at `index`.
+    pub fn player(&self, index: usize) -> Option<HeaplessVec<u8, 19>> {
+        self.players.get(index).map(decode_name)
+    }
+
+    /// Decodes the names of every player.
+    pub fn players(&self) -> impl Iterator<Item = HeaplessVec<u8, 19>> + 'a {
+        self.players.iter().map(decode_name)
+    }
 }
 
 /// A trn file, which contains names of tournaments.
@@ -118,6 +196,64 @@ impl Trn {
         write_names(&mut w, &self.tournaments, number_of_tournaments)?;
         Result::Ok(())
     }
+
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<TrnRef<'_>, ReadError> {
+        TrnRef::from_bytes(bytes)
+    }
+}
+
+/// A zero-copy view of a [`Trn`] parsed from an in-memory byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct TrnRef<'a> {
+    /// The centry when the file was created.
+    pub created_centry: u8,
+    /// The year when the file was created.
+    pub created_year: u8,
+    /// The month when the file was created.
+    pub created_month: u8,
+    /// The day when the file was created.
+    pub created_day: u8,
+    tournaments: &'a [[u8; 26]],
+}
+
+impl<'a> TrnRef<'a> {
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ReadError> {
+        let (header, remainder) = split::<u8, 16>(bytes).ok_or(ReadError::InvalidFormat)?;
+
+        let (created_centry, created_year, created_month, created_day, number_of_tournaments) =
+            decode_names_header(header)?;
+
+        Result::Ok(Self {
+            created_centry,
+            created_year,
+            created_month,
+            created_day,
+            tournaments: as_chunks(remainder, number_of_tournaments as usize)
+                .ok_or(ReadError::InvalidFormat)?,
+        })
+    }
+
+    /// The number of tournaments.
+    pub fn len(&self) -> usize {
+        self.tournaments.len()
+    }
+
+    /// Whether there are no tournaments.
+    pub fn is_empty(&self) -> bool {
+        self.tournaments.is_empty()
+    }
+
+    /// Decodes the name of the tournament at `index`.
+    pub fn tournament(&self, index: usize) -> Option<HeaplessVec<u8, 25>> {
+        self.tournaments.get(index).map(decode_tournament)
+    }
+
+    /// Decodes the names of every tournament.
+    pub fn tournaments(&self) -> impl Iterator<Item = HeaplessVec<u8, 25>> + 'a {
+        self.tournaments.iter().map(decode_tournament)
+    }
 }
 
 /// A wtb file, which contains `8x8` Othello games.
@@ -165,7 +301,7 @@ impl Wtb {
             created_day,
             year,
             calculation_depth,
-            games: read_games(&mut r, number_of_games)?,
+            games: GamesIter::ready(r, number_of_games).collect::<Result<_, _>>()?,
         })
     }
 
@@ -188,6 +324,96 @@ impl Wtb {
         write_games(&mut w, &self.games, number_of_games)?;
         Result::Ok(())
     }
+
+    /// Reads the header, then lazily yields one game per iteration instead of eagerly
+    /// collecting them into a [`Vec`], validating the trailing-byte invariant once the games
+    /// are exhausted.
+    pub fn games_iter(mut r: impl Read) -> impl Iterator<Item = Result<GameInfo, ReadError>> {
+        match read_games_header(&mut r) {
+            Result::Ok((_, _, _, _, number_of_games, _, size_of_board, _))
+                if size_of_board == 0 || size_of_board == 8 =>
+            {
+                GamesIter::ready(r, number_of_games)
+            },
+            Result::Ok(_) => GamesIter::failed(ReadError::InvalidFormat),
+            Result::Err(error) => GamesIter::failed(error),
+        }
+    }
+
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<WtbRef<'_>, ReadError> {
+        WtbRef::from_bytes(bytes)
+    }
+}
+
+/// A zero-copy view of a [`Wtb`] parsed from an in-memory byte slice.
+#[derive(Clone, Copy, Debug)]
+pub struct WtbRef<'a> {
+    /// The centry when the file was created.
+    pub created_centry: u8,
+    /// The year when the file was created.
+    pub created_year: u8,
+    /// The month when the file was created.
+    pub created_month: u8,
+    /// The day when the file was created.
+    pub created_day: u8,
+    /// The year when the games was played.
+    pub year: u16,
+    /// A number used to calculate [`GameInfo::theoretical_score`].
+    pub calculation_depth: u8,
+    games: &'a [[u8; 68]],
+}
+
+impl<'a> WtbRef<'a> {
+    /// Parses a file without copying `bytes`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, ReadError> {
+        let (header, remainder) = split::<u8, 16>(bytes).ok_or(ReadError::InvalidFormat)?;
+
+        let (
+            created_centry,
+            created_year,
+            created_month,
+            created_day,
+            number_of_games,
+            year,
+            size_of_board,
+            calculation_depth,
+        ) = decode_games_header(header)?;
+
+        if size_of_board != 0 && size_of_board != 8 {
+            return Result::Err(ReadError::InvalidFormat);
+        }
+
+        Result::Ok(Self {
+            created_centry,
+            created_year,
+            created_month,
+            created_day,
+            year,
+            calculation_depth,
+            games: as_chunks(remainder, number_of_games as usize).ok_or(ReadError::InvalidFormat)?,
+        })
+    }
+
+    /// The number of games.
+    pub fn len(&self) -> usize {
+        self.games.len()
+    }
+
+    /// Whether there are no games.
+    pub fn is_empty(&self) -> bool {
+        self.games.is_empty()
+    }
+
+    /// Decodes the game at `index`.
+    pub fn game(&self, index: usize) -> Option<Result<GameInfo, ReadError>> {
+        self.games.get(index).map(decode_game)
+    }
+
+    /// Decodes every game, one at a time, without allocating a [`Vec`] up front.
+    pub fn games(&self) -> impl Iterator<Item = Result<GameInfo, ReadError>> + 'a {
+        self.games.iter().map(decode_game)
+    }
 }
 
 /// A Othello game.
@@ -207,33 +433,83 @@ pub struct GameInfo {
     pub moves: HeaplessVec<Position, 60>,
 }
 
-fn read<const N: usize>(r: &mut impl Read) -> Result<[u8; N], ReadError> {
+impl GameInfo {
+    /// Renders the moves as a standard Othello transcript, e.g. `f5d6c3`.
+    pub fn transcript(&self) -> String {
+        self.moves
+            .iter()
+            .map(|r#move| format!("{}{}", (b'a' + r#move.column()) as char, r#move.row() + 1))
+            .collect()
+    }
+
+    /// Parses a standard Othello transcript, e.g. `f5d6c3`, into a move list.
+    pub fn from_transcript(
+        transcript: &str,
+    ) -> Result<HeaplessVec<Position, 60>, TranscriptError> {
+        let bytes = transcript.as_bytes();
+
+        if bytes.len() % 2 != 0 {
+            return Result::Err(TranscriptError::InvalidToken {
+                index: bytes.len() / 2,
+            });
+        }
+
+        if bytes.len() / 2 > 60 {
+            return Result::Err(TranscriptError::TooManyMoves);
+        }
+
+        bytes
+            .chunks(2)
+            .enumerate()
+            .map(|(index, token)| {
+                let column = token[0].to_ascii_lowercase();
+                let row = token[1];
+
+                if !(b'a'..=b'h').contains(&column) || !(b'1'..=b'8').contains(&row) {
+                    return Result::Err(TranscriptError::InvalidToken { index });
+                }
+
+                Position::at(row - b'1', column - b'a')
+                    .ok_or(TranscriptError::InvalidToken { index })
+            })
+            .collect()
+    }
+}
+
+fn read_buffer<const N: usize>(r: &mut impl Read) -> Result<[u8; N], ReadError> {
     let mut result = [0; N];
     r.read_exact(&mut result)?;
     Result::Ok(result)
 }
 
 #[allow(clippy::type_complexity)]
-fn read_header(
-    r: &mut impl Read,
-) -> Result<(u8, u8, u8, u8, u32, u16, u16, u8, u8, u8), ReadError> {
+fn decode_header(bytes: &[u8; 16]) -> Result<(u8, u8, u8, u8, u32, u16, u16, u8, u8, u8), ReadError> {
+    let mut cursor = Cursor::new(bytes);
+
     let result = (
-        read::<1>(r)?[0],
-        read::<1>(r)?[0],
-        read::<1>(r)?[0],
-        read::<1>(r)?[0],
-        u32::from_le_bytes(read(r)?),
-        u16::from_le_bytes(read(r)?),
-        u16::from_le_bytes(read(r)?),
-        read::<1>(r)?[0],
-        read::<1>(r)?[0],
-        read::<1>(r)?[0],
+        cursor.read_u8()?,
+        cursor.read_u8()?,
+        cursor.read_u8()?,
+        cursor.read_u8()?,
+        cursor.read_u32_le()?,
+        cursor.read_u16_le()?,
+        cursor.read_u16_le()?,
+        cursor.read_u8()?,
+        cursor.read_u8()?,
+        cursor.read_u8()?,
     );
 
-    read::<1>(r)?;
+    cursor.read_u8()?;
     Result::Ok(result)
 }
 
+#[allow(clippy::type_complexity)]
+fn read_header(
+    r: &mut impl Read,
+) -> Result<(u8, u8, u8, u8, u32, u16, u16, u8, u8, u8), ReadError> {
+    decode_header(&read_buffer(r)?)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn write_header(
     w: &mut impl Write,
@@ -248,15 +524,26 @@ fn write_header(
     p2: u8,
     p3: u8,
 ) -> Result<(), WriteError> {
-    w.write_all(&[created_centry, created_year, created_month, created_day])?;
-    w.write_all(&n1.to_le_bytes())?;
-    w.write_all(&n2.to_le_bytes())?;
-    w.write_all(&game_year.to_le_bytes())?;
-    w.write_all(&[p1, p2, p3, 0])?;
+    let mut buffer = Vec::with_capacity(16);
+    let mut writer = Writer::new(&mut buffer);
+
+    writer.put_u8(created_centry);
+    writer.put_u8(created_year);
+    writer.put_u8(created_month);
+    writer.put_u8(created_day);
+    writer.put_u32_le(n1);
+    writer.put_u16_le(n2);
+    writer.put_u16_le(game_year);
+    writer.put_u8(p1);
+    writer.put_u8(p2);
+    writer.put_u8(p3);
+    writer.put_u8(0);
+
+    w.write_all(&buffer)?;
     Result::Ok(())
 }
 
-fn read_names_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u16), ReadError> {
+fn decode_names_header(bytes: &[u8; 16]) -> Result<(u8, u8, u8, u8, u16), ReadError> {
     let (
         created_centry,
         created_year,
@@ -268,7 +555,7 @@ fn read_names_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u16), ReadErr
         p1,
         p2,
         _,
-    ) = read_header(r)?;
+    ) = decode_header(bytes)?;
 
     if n1 != 0 || game_year != 0 || p1 != 0 || p2 != 0 {
         return Result::Err(ReadError::InvalidFormat);
@@ -283,6 +570,10 @@ fn read_names_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u16), ReadErr
     ))
 }
 
+fn read_names_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u16), ReadError> {
+    decode_names_header(&read_buffer(r)?)
+}
+
 fn write_names_header(
     w: &mut impl Write,
     created_centry: u8,
@@ -307,7 +598,7 @@ fn write_names_header(
 }
 
 #[allow(clippy::type_complexity)]
-fn read_games_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u32, u16, u8, u8), ReadError> {
+fn decode_games_header(bytes: &[u8; 16]) -> Result<(u8, u8, u8, u8, u32, u16, u8, u8), ReadError> {
     let (
         created_centry,
         created_year,
@@ -319,7 +610,7 @@ fn read_games_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u32, u16, u8,
         size_of_board,
         game_type,
         calculation_depth,
-    ) = read_header(r)?;
+    ) = decode_header(bytes)?;
 
     if n2 != 0 || game_type != 0 {
         return Result::Err(ReadError::InvalidFormat);
@@ -337,6 +628,11 @@ fn read_games_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u32, u16, u8,
     ))
 }
 
+#[allow(clippy::type_complexity)]
+fn read_games_header(r: &mut impl Read) -> Result<(u8, u8, u8, u8, u32, u16, u8, u8), ReadError> {
+    decode_games_header(&read_buffer(r)?)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn write_games_header(
     w: &mut impl Write,
@@ -364,23 +660,31 @@ fn write_games_header(
     )
 }
 
+fn decode_name(record: &[u8; 20]) -> HeaplessVec<u8, 19> {
+    Cursor::new(&record[..19])
+        .read_zero_padded_name::<19>()
+        .unwrap()
+}
+
+fn decode_tournament(record: &[u8; 26]) -> HeaplessVec<u8, 25> {
+    Cursor::new(&record[..25])
+        .read_zero_padded_name::<25>()
+        .unwrap()
+}
+
 fn read_names<const N: usize>(
     r: &mut impl Read,
     count: u16,
 ) -> Result<Vec<HeaplessVec<u8, N>>, ReadError> {
     let result = (0..count)
         .map(|_| {
-            let result = read::<N>(r)?
-                .into_iter()
-                .take_while(|c| *c != b'0')
-                .collect();
-
-            read::<1>(r)?;
-            Result::Ok(result)
+            let name = Cursor::new(&read_buffer::<N>(r)?).read_zero_padded_name::<N>()?;
+            read_buffer::<1>(r)?;
+            Result::Ok(name)
         })
         .collect();
 
-    if read::<1>(r).is_ok() {
+    if read_buffer::<1>(r).is_ok() {
         return Result::Err(ReadError::InvalidFormat);
     }
 
@@ -397,49 +701,130 @@ fn write_names<const N: usize>(
     }
 
     for name in names {
-        let mut name = name.clone();
-        name.extend(repeat(b'0').take(N - name.len()));
-        w.write_all(&name)?;
-        w.write_all(&[b'0'])?;
+        let mut buffer = Vec::with_capacity(N + 1);
+        let mut writer = Writer::new(&mut buffer);
+
+        writer.put_zero_padded_name(name);
+        writer.put_u8(b'0');
+        w.write_all(&buffer)?;
     }
 
     Result::Ok(())
 }
 
-fn read_games(r: &mut impl Read, count: u32) -> Result<Vec<GameInfo>, ReadError> {
-    let result = (0..count)
-        .map(|_| {
-            Result::Ok(GameInfo {
-                tournament: u16::from_le_bytes(read(r)?),
-                black_player: u16::from_le_bytes(read(r)?),
-                white_player: u16::from_le_bytes(read(r)?),
-                score: read::<1>(r)?[0],
-                theoretical_score: read::<1>(r)?[0],
-                moves: {
-                    let moves: HeaplessVec<_, 60> = read::<60>(r)?
-                        .into_iter()
-                        .take_while(|r#move| *r#move != 0)
-                        .map(|r#move| Position::at(r#move / 10 - 1, r#move % 10 - 1))
-                        .collect::<Option<_>>()
-                        .ok_or(ReadError::InvalidFormat)?;
-
-                    if let Option::Some(r#move) = moves.iter().next() {
-                        if *r#move != Position::at(4, 5).unwrap() {
-                            return Result::Err(ReadError::InvalidFormat);
-                        }
-                    }
-
-                    moves
-                },
-            })
-        })
-        .collect();
+fn decode_game(bytes: &[u8; 68]) -> Result<GameInfo, ReadError> {
+    let mut cursor = Cursor::new(bytes);
+
+    let tournament = cursor.read_u16_le()?;
+    let black_player = cursor.read_u16_le()?;
+    let white_player = cursor.read_u16_le()?;
+    let score = cursor.read_u8()?;
+    let theoretical_score = cursor.read_u8()?;
+
+    let moves: HeaplessVec<_, 60> = cursor
+        .read_bytes::<60>()?
+        .into_iter()
+        .take_while(|r#move| *r#move != 0)
+        .map(|r#move| Position::at(r#move / 10 - 1, r#move % 10 - 1))
+        .collect::<Option<_>>()
+        .ok_or(ReadError::InvalidFormat)?;
+
+    if let Option::Some(r#move) = moves.iter().next() {
+        if *r#move != Position::at(4, 5).unwrap() {
+            return Result::Err(ReadError::InvalidFormat);
+        }
+    }
 
-    if read::<1>(r).is_ok() {
-        return Result::Err(ReadError::InvalidFormat);
+    Result::Ok(GameInfo {
+        tournament,
+        black_player,
+        white_player,
+        score,
+        theoretical_score,
+        moves,
+    })
+}
+
+fn read_game(r: &mut impl Read) -> Result<GameInfo, ReadError> {
+    decode_game(&read_buffer(r)?)
+}
+
+struct GamesIterReady<R> {
+    r: R,
+    remaining: u32,
+}
+
+struct GamesIter<R> {
+    state: Result<GamesIterReady<R>, Option<ReadError>>,
+}
+
+impl<R> GamesIter<R> {
+    fn ready(r: R, remaining: u32) -> Self {
+        Self {
+            state: Result::Ok(GamesIterReady { r, remaining }),
+        }
     }
 
-    result
+    fn failed(error: ReadError) -> Self {
+        Self {
+            state: Result::Err(Option::Some(error)),
+        }
+    }
+}
+
+impl<R: Read> Iterator for GamesIter<R> {
+    type Item = Result<GameInfo, ReadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ready = match &mut self.state {
+            Result::Err(error) => return error.take().map(Result::Err),
+            Result::Ok(ready) => ready,
+        };
+
+        if ready.remaining == 0 {
+            let result = match read_buffer::<1>(&mut ready.r) {
+                Result::Ok(_) => Option::Some(Result::Err(ReadError::InvalidFormat)),
+                Result::Err(_) => Option::None,
+            };
+
+            self.state = Result::Err(Option::None);
+            return result;
+        }
+
+        ready.remaining -= 1;
+
+        match read_game(&mut ready.r) {
+            Result::Ok(game) => Option::Some(Result::Ok(game)),
+            Result::Err(error) => {
+                self.state = Result::Err(Option::None);
+                Option::Some(Result::Err(error))
+            },
+        }
+    }
+}
+
+fn write_game(w: &mut impl Write, game: &GameInfo) -> Result<(), WriteError> {
+    let mut buffer = Vec::with_capacity(68);
+    let mut writer = Writer::new(&mut buffer);
+
+    writer.put_u16_le(game.tournament);
+    writer.put_u16_le(game.black_player);
+    writer.put_u16_le(game.white_player);
+    writer.put_u8(game.score);
+    writer.put_u8(game.theoretical_score);
+
+    writer.put_bytes(
+        &game
+            .moves
+            .iter()
+            .map(|r#move| 10 * r#move.row() + r#move.column() + 11)
+            .chain(repeat(0))
+            .take(60)
+            .collect::<HeaplessVec<_, 60>>(),
+    );
+
+    w.write_all(&buffer)?;
+    Result::Ok(())
 }
 
 fn write_games(w: &mut impl Write, games: &[GameInfo], count: u32) -> Result<(), WriteError> {
@@ -448,20 +833,7 @@ fn write_games(w: &mut impl Write, games: &[GameInfo], count: u32) -> Result<(),
     }
 
     for game in games {
-        w.write_all(&game.tournament.to_le_bytes())?;
-        w.write_all(&game.black_player.to_le_bytes())?;
-        w.write_all(&game.white_player.to_le_bytes())?;
-        w.write_all(&[game.score, game.theoretical_score])?;
-
-        w.write_all(
-            &game
-                .moves
-                .iter()
-                .map(|r#move| 10 * r#move.row() + r#move.column() + 11)
-                .chain(repeat(0))
-                .take(60)
-                .collect::<HeaplessVec<_, 60>>(),
-        )?;
+        write_game(w, game)?;
     }
 
     Result::Ok(())
@@ -472,6 +844,8 @@ fn write_games(w: &mut impl Write, games: &[GameInfo], count: u32) -> Result<(),
 pub enum ReadError {
     /// The input is an invalid file.
     InvalidFormat,
+    /// See [`CursorError`].
+    Cursor(CursorError),
     /// See [`Error`](IoError).
     Io(IoError),
 }
@@ -480,6 +854,7 @@ impl Display for ReadError {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
         match self {
             Self::InvalidFormat => formatter.write_str("the input is invalid"),
+            Self::Cursor(error) => error.fmt(formatter),
             Self::Io(error) => error.fmt(formatter),
         }
     }
@@ -487,6 +862,12 @@ impl Display for ReadError {
 
 impl Error for ReadError {}
 
+impl From<CursorError> for ReadError {
+    fn from(error: CursorError) -> Self {
+        Self::Cursor(error)
+    }
+}
+
 impl From<IoError> for ReadError {
     fn from(error: IoError) -> Self {
         Self::Io(error)
@@ -518,3 +899,73 @@ impl From<IoError> for WriteError {
         Self::Io(error)
     }
 }
+
+/// An error while parsing a transcript.
+#[derive(Debug)]
+pub enum TranscriptError {
+    /// The move at `index` was malformed or out of range.
+    InvalidToken {
+        /// The index of the offending move.
+        index: usize,
+    },
+    /// The transcript has more than 60 moves.
+    TooManyMoves,
+}
+
+impl Display for TranscriptError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::InvalidToken { index } => {
+                write!(formatter, "the move at index {index} is invalid")
+            },
+            Self::TooManyMoves => formatter.write_str("the transcript has too many moves"),
+        }
+    }
+}
+
+impl Error for TranscriptError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcript_round_trips() {
+        let moves = GameInfo::from_transcript("f5d6c3").unwrap();
+
+        let info = GameInfo {
+            tournament: 0,
+            black_player: 0,
+            white_player: 0,
+            score: 0,
+            theoretical_score: 0,
+            moves,
+        };
+
+        assert_eq!(info.transcript(), "f5d6c3");
+    }
+
+    #[test]
+    fn from_transcript_accepts_an_empty_transcript() {
+        assert_eq!(
+            GameInfo::from_transcript("").unwrap(),
+            HeaplessVec::<Position, 60>::new(),
+        );
+    }
+
+    #[test]
+    fn from_transcript_rejects_an_odd_length_transcript() {
+        assert!(matches!(
+            GameInfo::from_transcript("f5d"),
+            Result::Err(TranscriptError::InvalidToken { index: 1 }),
+        ));
+    }
+
+    #[test]
+    fn from_transcript_rejects_an_out_of_range_token() {
+        assert!(matches!(
+            GameInfo::from_transcript("z9"),
+            Result::Err(TranscriptError::InvalidToken { index: 0 }),
+        ));
+    }
+}
@@ -38,9 +38,11 @@ pub fn as_array_mut<T, const N: usize>(bytes: &mut [T]) -> Option<&mut [T; N]> {
 }
 
 pub fn as_chunks<T, const N: usize>(bytes: &[T], count: usize) -> Option<&[[T; N]]> {
-    match bytes.len() == N * count {
-        true => Option::Some(unsafe { from_raw_parts(bytes.as_ptr().cast(), count) }),
-        false => Option::None,
+    match N.checked_mul(count) {
+        Option::Some(len) if bytes.len() == len => {
+            Option::Some(unsafe { from_raw_parts(bytes.as_ptr().cast(), count) })
+        },
+        _ => Option::None,
     }
 }
 